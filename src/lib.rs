@@ -1,7 +1,7 @@
 //! The `SuccinctVec` behaves like a `Vec` with smaller asymptotic memory overhead.
 //! It offers amortized constant time `push` and `pop` and constant worst-case time indexed access with `O(sqrt n)` asymptotic memory overhead.
 
-use std::ops::{Index, IndexMut};
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
 
 /// `SuccinctVec` guarantees `O(1)` amortized `push` and `pop` and worst-case `O(1)` indexed access.
 /// The memory overhead is guaranteed to be `O(sqrt n)` where `n` is the length of the data structure, in contrast to `Vec`s linear overhead.
@@ -31,29 +31,190 @@ pub struct SuccinctVec<T> {
     s_odd: bool,
 
     // length of super block (amount of data blocks)
-    len_last_super: usize, 
+    len_last_super: usize,
     // capacity of super block (amount of data blocks)
-    cap_last_super: usize, 
-    empty_data_block: Option<Vec<T>> 
+    cap_last_super: usize,
+    empty_data_block: Option<Vec<T>>,
+    // data blocks pre-allocated by `reserve`/`try_reserve`, paired with the capacity the
+    // recurrence intended for them, consumed in order by `grow_block` so that the pushes that
+    // follow never need to hit the allocator. A block whose intended capacity no longer matches
+    // what `grow_block` computes (e.g. a `pop` rewound the recurrence since `reserve` ran) is
+    // stale and gets discarded instead of used.
+    reserved_blocks: std::collections::VecDeque<(Vec<T>, usize)>,
+
+    // Mirrors the back (data_blocks) bookkeeping above, but for the front of the deque. Blocks
+    // are appended to `front_blocks` the same way blocks are appended to `data_blocks`; a block's
+    // elements and the block order itself are both stored back-to-front, so that logical index 0
+    // (the current front of the vector) is always the last element of `front_blocks.last()`, and
+    // reading forward through `front_blocks` in reverse recovers the logical front region in order.
+    front_blocks: Vec<Vec<T>>,
+    // number of elements currently stored in the front region
+    front_len: usize,
+    // true iff number of front superblocks is odd
+    front_s_odd: bool,
+    // length of the first superblock (amount of front data blocks)
+    len_first_super: usize,
+    // capacity of the first superblock (amount of front data blocks)
+    cap_first_super: usize,
+    empty_front_block: Option<Vec<T>>,
+}
+
+/// Error type returned by [`SuccinctVec::try_reserve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The new capacity would overflow `usize`.
+    CapacityOverflow,
+    /// The allocator reported an allocation failure.
+    AllocError,
+}
+
+impl std::fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            TryReserveError::AllocError => write!(f, "memory allocation failed"),
+        }
+    }
 }
 
+impl std::error::Error for TryReserveError {}
+
 impl<T> SuccinctVec<T> {
     /// Returns the number of elements in the vector, also referred to as its 'length'.
     pub fn len(&self) -> usize {
-        self.len
+        self.front_len + self.len
     }
-    
+
     /// Returns `true` if the vector contains no elements.
     pub fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
 
     /// Returns the number of elements the array can hold without reallocating (including the reserve data block)
     pub fn capacity(&self) -> usize {
-        0 + match &self.empty_data_block {
-            None => { 0 },
-            Some(vec) => { vec.len() },
-        } + if self.data_blocks.is_empty() { 0 } else { self.len - self.data_blocks.last().unwrap().len() + self.data_blocks.last().unwrap().capacity() }
+        let empty_block_capacity = match &self.empty_data_block {
+            None => 0,
+            Some(vec) => vec.capacity(),
+        };
+        let empty_front_block_capacity = match &self.empty_front_block {
+            None => 0,
+            Some(vec) => vec.capacity(),
+        };
+        let data_blocks_capacity: usize = self.data_blocks.iter().map(|vec| vec.capacity()).sum();
+        let front_blocks_capacity: usize = self.front_blocks.iter().map(|vec| vec.capacity()).sum();
+        let reserved_capacity: usize = self.reserved_blocks.iter().map(|(vec, _)| vec.capacity()).sum();
+        empty_block_capacity + empty_front_block_capacity + data_blocks_capacity + front_blocks_capacity + reserved_capacity
+    }
+
+    /// Reserves capacity for at least `additional` more elements, pre-allocating the data blocks
+    /// that future pushes will need so that they never have to hit the allocator.
+    ///
+    /// # Panics
+    /// Panics if the new capacity exceeds `usize::MAX` elements or if the allocator reports an
+    /// allocation failure. Use [`Self::try_reserve`] for a fallible version.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).expect("allocation failed")
+    }
+
+    /// Fallible version of [`Self::reserve`].
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.len()
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        // Only the back region's blocks can take pushes; spare room in the front region does not count.
+        let back_capacity: usize = self.data_blocks.iter().map(|vec| vec.capacity()).sum::<usize>()
+            + self.empty_data_block.as_ref().map_or(0, |vec| vec.capacity())
+            + self.reserved_blocks.iter().map(|(vec, _)| vec.capacity()).sum::<usize>();
+        let spare = back_capacity - self.len;
+        if spare >= additional {
+            return Ok(());
+        }
+        let mut needed = additional - spare;
+
+        // Simulate the block/superblock recurrence forward using local copies of the bookkeeping
+        // state, so the real `s_odd`/`len_last_super`/`cap_last_super` only ever advance lazily,
+        // exactly when `grow_block` really appends a block.
+        let (mut s_odd, mut len_last_super, mut cap_last_super, mut last_cap, mut have_block) =
+            self.reserve_simulation_state();
+
+        while needed > 0 {
+            let cap = if !have_block {
+                have_block = true;
+                s_odd = true;
+                len_last_super = 1;
+                cap_last_super = 1;
+                1
+            } else {
+                let (new_s_odd, new_len_last_super, new_cap_last_super, cap) =
+                    Self::next_block_state(s_odd, len_last_super, cap_last_super, last_cap);
+                s_odd = new_s_odd;
+                len_last_super = new_len_last_super;
+                cap_last_super = new_cap_last_super;
+                cap
+            };
+
+            let mut block = Vec::new();
+            block
+                .try_reserve_exact(cap)
+                .map_err(|_| TryReserveError::AllocError)?;
+            // `try_reserve_exact` only guarantees capacity >= `cap`, so the allocator may hand
+            // back a block that doesn't match the capacity the recurrence assigned it; rather
+            // than assert that here (which would make this fallible path panic), the intended
+            // `cap` travels with the block and `grow_block` re-validates it before use, discarding
+            // a mismatched block instead of trusting its real capacity.
+            last_cap = cap;
+            needed = needed.saturating_sub(cap);
+            self.reserved_blocks.push_back((block, cap));
+        }
+        Ok(())
+    }
+
+    /// Resumes the `next_block_state` recurrence at the point the next real block transition
+    /// will occur: from the real last data block, fast-forwarded through any blocks already
+    /// queued up in `reserved_blocks` by a previous `reserve` call. When the vector has never
+    /// held a block yet, the first one is free (reused from `empty_data_block`), so the
+    /// recurrence resumes right after it instead of allocating a redundant duplicate.
+    ///
+    /// `grow_block` always drains `empty_data_block` before `reserved_blocks`, so a spare empty
+    /// block sitting next to a real (full) last data block — e.g. right after a `pop` emptied the
+    /// previous last block — accounts for one more recurrence step that `grow_block` will satisfy
+    /// for free, before any `reserved_blocks` entry is reached.
+    fn reserve_simulation_state(&self) -> (bool, usize, usize, usize, bool) {
+        let (mut s_odd, mut len_last_super, mut cap_last_super, mut last_cap) =
+            match self.data_blocks.last() {
+                Some(last) => (self.s_odd, self.len_last_super, self.cap_last_super, last.capacity()),
+                None if self.empty_data_block.is_some() => (true, 1, 1, 1),
+                None => (true, 0, 1, 0),
+            };
+        let have_block = self.data_blocks.last().is_some() || self.empty_data_block.is_some();
+
+        if self.data_blocks.last().is_some() && self.empty_data_block.is_some() {
+            let (next_s_odd, next_len_last_super, next_cap_last_super, next_cap) =
+                Self::next_block_state(s_odd, len_last_super, cap_last_super, last_cap);
+            s_odd = next_s_odd;
+            len_last_super = next_len_last_super;
+            cap_last_super = next_cap_last_super;
+            last_cap = next_cap;
+        }
+
+        for _ in 0..self.reserved_blocks.len() {
+            let (next_s_odd, next_len_last_super, next_cap_last_super, next_cap) =
+                Self::next_block_state(s_odd, len_last_super, cap_last_super, last_cap);
+            s_odd = next_s_odd;
+            len_last_super = next_len_last_super;
+            cap_last_super = next_cap_last_super;
+            last_cap = next_cap;
+        }
+
+        (s_odd, len_last_super, cap_last_super, last_cap, have_block)
+    }
+
+    /// Creates an empty `SuccinctVec` with at least the given capacity pre-allocated.
+    pub fn with_capacity(n: usize) -> Self {
+        let mut vec = Self::default();
+        vec.reserve(n);
+        vec
     }
 
     /// Appends an element to the back of a collection.
@@ -64,23 +225,56 @@ impl<T> SuccinctVec<T> {
 
     /// Removes the last element from a vector and returns it, or [`None`] if it is empty.
     pub fn pop(&mut self) -> Option<T> {
-        if self.is_empty() {
-            return None;
+        if self.len > 0 {
+            let result = self.data_blocks.last_mut().unwrap().pop();
+            self.shrink();
+            result
+        } else if !self.is_empty() {
+            Some(self.remove(self.len() - 1))
+        } else {
+            None
         }
+    }
 
-        let result = self.data_blocks.last_mut().unwrap().pop();
-        self.shrink();
-        result
+    /// Prepends an element to the front of the vector in amortized `O(1)`.
+    pub fn push_front(&mut self, value: T) {
+        self.grow_front();
+        self.front_blocks.last_mut().unwrap().push(value);
+    }
+
+    /// Removes the first element of the vector and returns it, or [`None`] if it is empty, in
+    /// amortized `O(1)`.
+    ///
+    /// Falls back to [`Self::remove`]`(0)` (`O(n)`) when the front region is currently
+    /// empty but the back region still holds elements.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.front_len > 0 {
+            let result = self.front_blocks.last_mut().unwrap().pop();
+            self.shrink_front();
+            result
+        } else if !self.is_empty() {
+            Some(self.remove(0))
+        } else {
+            None
+        }
     }
 
     /// Returns the last element of the slice, or None if it is empty.
     pub fn last(&self) -> Option<&T> {
-        self.data_blocks.last().and_then(|vec| vec.last())
+        if self.len == 0 {
+            self.front_blocks.first().and_then(|vec| vec.first())
+        } else {
+            self.data_blocks.last().and_then(|vec| vec.last())
+        }
     }
 
     /// Returns a mutable pointer to the last item in the slice.
     pub fn last_mut(&mut self) -> Option<&mut T> {
-        self.data_blocks.last_mut().and_then(|vec| vec.last_mut())
+        if self.len == 0 {
+            self.front_blocks.first_mut().and_then(|vec| vec.first_mut())
+        } else {
+            self.data_blocks.last_mut().and_then(|vec| vec.last_mut())
+        }
     }
 
     /// Changes the state of the vector such that it can fit a new element at the end of the data structure (using push).
@@ -88,10 +282,16 @@ impl<T> SuccinctVec<T> {
     fn grow(&mut self) {
         // The implementation follows the paper closely.
         self.len += 1;
+        self.grow_block();
+    }
 
+    /// Runs the block/superblock transition that `grow` needs whenever the last data block is
+    /// full, without touching `len`. Reuses a block pre-allocated by `reserve` before falling
+    /// back to the allocator, so a prior `reserve` call makes this allocation-free.
+    fn grow_block(&mut self) {
         // added a necessary special case for the empty vector
         if self.data_blocks.is_empty() {
-            self.len_last_super += 1;
+            self.len_last_super = 1;
             self.cap_last_super = 1;
             self.s_odd = true;
             self.data_blocks.push(self.empty_data_block.take().unwrap());
@@ -100,26 +300,50 @@ impl<T> SuccinctVec<T> {
 
         // 1. If the last nonempty data block DB[d-1] is full
         if self.data_blocks.last().unwrap().capacity() == self.data_blocks.last().unwrap().len() {
-            let mut cap = self.data_blocks.last().unwrap().capacity();
+            let last_cap = self.data_blocks.last().unwrap().capacity();
             // (a) If the last superblock SB[s-1] is full, add a new virtual superblock
-            if self.len_last_super == self.cap_last_super {
-                self.s_odd = !self.s_odd;
-                if self.s_odd {
-                    self.cap_last_super *= 2;
-                } else {
-                    cap *= 2;
-                }
-                self.len_last_super = 0;
-            }
-            self.len_last_super += 1;
+            let (s_odd, len_last_super, cap_last_super, cap) =
+                Self::next_block_state(self.s_odd, self.len_last_super, self.cap_last_super, last_cap);
+            self.s_odd = s_odd;
+            self.len_last_super = len_last_super;
+            self.cap_last_super = cap_last_super;
 
-            // (b) If there are no empty data blocks
-            match self.empty_data_block.take() {
-                Some(x) => {  self.data_blocks.push(x); }, 
-                None => { self.data_blocks.push(Vec::with_capacity(cap)); },
-            }
+            // (b) If there are no empty/reserved data blocks, allocate a fresh one. A reserved
+            // block whose intended capacity no longer matches `cap` is stale (e.g. a `pop` rewound
+            // the recurrence after `reserve` queued it up) and gets discarded rather than used, to
+            // avoid desyncing `locate`.
+            let block = match self.empty_data_block.take() {
+                Some(x) => x,
+                None => match self.reserved_blocks.pop_front() {
+                    Some((block, reserved_cap)) if reserved_cap == cap => block,
+                    _ => Vec::with_capacity(cap),
+                },
+            };
+            self.data_blocks.push(block);
+        }
+    }
 
+    /// Pure step of the superblock/data-block recurrence used by `grow_block`: given the current
+    /// bookkeeping state and the capacity of the block that just became full, returns the updated
+    /// state together with the capacity the next data block should have. Factored out so
+    /// `try_reserve` can replay the same recurrence on a local copy of the state to size
+    /// pre-allocated blocks, without prematurely advancing the real bookkeeping.
+    fn next_block_state(s_odd: bool, len_last_super: usize, cap_last_super: usize, last_block_cap: usize) -> (bool, usize, usize, usize) {
+        let mut cap = last_block_cap;
+        let mut s_odd = s_odd;
+        let mut len_last_super = len_last_super;
+        let mut cap_last_super = cap_last_super;
+        if len_last_super == cap_last_super {
+            s_odd = !s_odd;
+            if s_odd {
+                cap_last_super *= 2;
+            } else {
+                cap *= 2;
+            }
+            len_last_super = 0;
         }
+        len_last_super += 1;
+        (s_odd, len_last_super, cap_last_super, cap)
     }
 
     /// Changes the state of the vector such that it has an element less at the end. 
@@ -148,6 +372,55 @@ impl<T> SuccinctVec<T> {
         //result
     }
 
+    /// Mirrors [`Self::grow`] for the front of the deque: grows `front_blocks` so it can fit a
+    /// new element at the front (using `push_front`).
+    fn grow_front(&mut self) {
+        self.front_len += 1;
+        self.grow_front_block();
+    }
+
+    /// Mirrors [`Self::grow_block`] for the front of the deque.
+    fn grow_front_block(&mut self) {
+        if self.front_blocks.is_empty() {
+            self.len_first_super = 1;
+            self.cap_first_super = 1;
+            self.front_s_odd = true;
+            self.front_blocks.push(self.empty_front_block.take().unwrap());
+            return;
+        }
+
+        if self.front_blocks.last().unwrap().capacity() == self.front_blocks.last().unwrap().len() {
+            let last_cap = self.front_blocks.last().unwrap().capacity();
+            let (s_odd, len_last_super, cap_last_super, cap) =
+                Self::next_block_state(self.front_s_odd, self.len_first_super, self.cap_first_super, last_cap);
+            self.front_s_odd = s_odd;
+            self.len_first_super = len_last_super;
+            self.cap_first_super = cap_last_super;
+
+            match self.empty_front_block.take() {
+                Some(x) => { self.front_blocks.push(x); },
+                None => { self.front_blocks.push(Vec::with_capacity(cap)); },
+            }
+        }
+    }
+
+    /// Mirrors [`Self::shrink`] for the front of the deque.
+    fn shrink_front(&mut self) {
+        if self.front_blocks.last().unwrap().is_empty() {
+            self.empty_front_block = self.front_blocks.pop();
+            self.len_first_super -= 1;
+            if self.len_first_super == 0 {
+                self.front_s_odd = !self.front_s_odd;
+                if !self.front_s_odd {
+                    self.cap_first_super /= 2;
+                }
+                self.len_first_super = self.cap_first_super;
+            }
+        }
+
+        self.front_len -= 1;
+    }
+
     /// Given the `index` it calculates the position of corresponding data block and the position inside this data block
     fn locate(index: usize) -> (usize, usize) {
         // The implementation follows the paper closely. It uses some small optimizations for Intel CPUs though.
@@ -176,17 +449,61 @@ impl<T> SuccinctVec<T> {
 
     /// Returns an iterator over the slice.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data_blocks.iter().flat_map(|x| x.iter())
+        self.front_blocks
+            .iter()
+            .rev()
+            .flat_map(|x| x.iter().rev())
+            .chain(self.data_blocks.iter().flat_map(|x| x.iter()))
     }
 
 
     /// Returns an iterator that allows modifying each value.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data_blocks.iter_mut().flat_map(|x| x.iter_mut())
+        self.front_blocks
+            .iter_mut()
+            .rev()
+            .flat_map(|x| x.iter_mut().rev())
+            .chain(self.data_blocks.iter_mut().flat_map(|x| x.iter_mut()))
+    }
+
+    /// Returns an iterator over the contiguous data blocks backing the back region of this
+    /// vector, each yielded as a slice. Useful for block-at-a-time operations like serialization
+    /// or numeric batch loads.
+    ///
+    /// Only covers the back region (the blocks [`Self::push`]/[`Self::pop`] operate on); it does
+    /// not see elements held in the front region by [`Self::push_front`]. Use [`Self::iter`] for
+    /// a full logical traversal of a vector that may have a non-empty front region.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the front region is non-empty, since the back-region-only blocks
+    /// would silently omit those elements.
+    pub fn chunks(&self) -> impl Iterator<Item = &[T]> {
+        debug_assert_eq!(self.front_len, 0, "chunks() only covers the back region; use iter() when push_front has been used");
+        self.data_blocks.iter().map(|block| block.as_slice())
+    }
+
+    /// Returns an iterator over the contiguous data blocks backing the back region of this
+    /// vector, each yielded as a mutable slice. See [`Self::chunks`] for the front-region caveat.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the front region is non-empty, since the back-region-only blocks
+    /// would silently omit those elements.
+    pub fn chunks_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        debug_assert_eq!(self.front_len, 0, "chunks_mut() only covers the back region; use iter_mut() when push_front has been used");
+        self.data_blocks.iter_mut().map(|block| block.as_mut_slice())
     }
 
     /// Inserts an element at position index within the vector, shifting all elements after it to the right.
     pub fn insert(&mut self, index: usize, element: T) {
+        if index < self.front_len {
+            self.insert_front_region(index, element);
+        } else {
+            self.insert_back_region(index - self.front_len, element);
+        }
+    }
+
+    /// Handles [`Self::insert`] for an `index` that falls in the back region (`data_blocks`).
+    fn insert_back_region(&mut self, index: usize, element: T) {
         let (a, b) = SuccinctVec::<T>::locate(index);
 
         // We move the last element of a data block to the first position of the next data block, from back to front to prevent the data blocks from growing
@@ -195,16 +512,44 @@ impl<T> SuccinctVec<T> {
             let elem_to_move = self.data_blocks[data_block - 1].pop().unwrap();
             self.data_blocks[data_block].insert(0, elem_to_move);
         }
-    
+
         let cap = self.data_blocks[a].capacity();
         self.data_blocks[a].insert(b, element);
         assert_eq!(cap, self.data_blocks[a].capacity());
     }
 
+    /// Handles [`Self::insert`] for a `front_index` that falls in the front region
+    /// (`front_blocks`). `front_blocks` grows the same way `data_blocks` does (by appending),
+    /// just storing the front region in reverse; a front-local index is translated into the
+    /// equivalent forward position before reusing the same `locate`-based shifting dance.
+    fn insert_front_region(&mut self, front_index: usize, element: T) {
+        let pos = self.front_len - front_index;
+        let (a, b) = Self::locate(pos);
+
+        self.grow_front();
+        for block in (a+1..self.front_blocks.len()).rev() {
+            let elem_to_move = self.front_blocks[block - 1].pop().unwrap();
+            self.front_blocks[block].insert(0, elem_to_move);
+        }
+
+        let cap = self.front_blocks[a].capacity();
+        self.front_blocks[a].insert(b, element);
+        assert_eq!(cap, self.front_blocks[a].capacity());
+    }
+
     /// Removes and returns the element at position index within the vector, shifting all elements after it to the left.
     /// # Panics
     /// Panics if index `is` out of bounds.
     pub fn remove(&mut self, index: usize) -> T {
+        if index < self.front_len {
+            self.remove_front_region(index)
+        } else {
+            self.remove_back_region(index - self.front_len)
+        }
+    }
+
+    /// Handles [`Self::remove`] for an `index` that falls in the back region (`data_blocks`).
+    fn remove_back_region(&mut self, index: usize) -> T {
         let (a, b) = Self::locate(index);
         let result = self.data_blocks[a].remove(b);
 
@@ -217,45 +562,418 @@ impl<T> SuccinctVec<T> {
         result
     }
 
+    /// Handles [`Self::remove`] for a `front_index` that falls in the front region
+    /// (`front_blocks`), mirroring [`Self::remove_back_region`]; see
+    /// [`Self::insert_front_region`] for how a front-local index maps to a forward position.
+    fn remove_front_region(&mut self, front_index: usize) -> T {
+        let pos = self.front_len - 1 - front_index;
+        let (a, b) = Self::locate(pos);
+        let result = self.front_blocks[a].remove(b);
+
+        for block in a+1..self.front_blocks.len() {
+            let temp = self.front_blocks[block].remove(0);
+            self.front_blocks[block - 1].push(temp);
+        }
+
+        self.shrink_front();
+        result
+    }
+
     /// Removes the element at `index` and returns it.
     /// The removed element is replaced by `replacement`.
     pub fn swap_replace(&mut self, index: usize, replacement: T) -> T {
         // TODO replace this with unsafe code swapping the elements in the data block directly (or find an appropiate method in `Vec`)
-        let (a, b) = Self::locate(index);
-        let last = self.data_blocks[a].pop().unwrap();
-        self.data_blocks[a].push(replacement);
-        let result = self.data_blocks[a].swap_remove(b);
-        self.data_blocks[a].push(last);
+        let (blocks, a, b) = if index < self.front_len {
+            let pos = self.front_len - 1 - index;
+            let (a, b) = Self::locate(pos);
+            (&mut self.front_blocks, a, b)
+        } else {
+            let (a, b) = Self::locate(index - self.front_len);
+            (&mut self.data_blocks, a, b)
+        };
+        let last = blocks[a].pop().unwrap();
+        blocks[a].push(replacement);
+        let result = blocks[a].swap_remove(b);
+        blocks[a].push(last);
         result
     }
 
     
+    /// Removes the specified range from the vector, returning the removed elements as an iterator.
+    ///
+    /// The gap left by the removed elements is closed immediately, at call time, by repeatedly
+    /// calling [`Self::remove`] (`O((end - start) * n)`) and buffering the results; unlike
+    /// `Vec::drain`, compaction does not wait for the returned [`Drain`] to be dropped or advanced.
+    /// This means the vector is already back in a consistent state by the time `drain` returns, so
+    /// dropping or forgetting (e.g. via [`std::mem::forget`]) the returned iterator without
+    /// consuming it is always safe, at the cost of removing elements that are never actually
+    /// iterated.
+    ///
+    /// # Panics
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the vector.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "drain start must not exceed end");
+        assert!(end <= len, "drain end out of bounds");
+
+        let mut drained = Vec::with_capacity(end - start);
+        for _ in start..end {
+            drained.push(self.remove(start));
+        }
+
+        Drain {
+            iter: drained.into_iter(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Appends the elements of `other` to the back of the vector.
+    ///
+    /// Fills the current last data block up to capacity with a `copy_from_slice`-style bulk
+    /// copy, then grows a new block and repeats, instead of calling [`Self::push`] per element.
+    /// This turns a bulk append into `O(sqrt n)` block fills rather than `O(n)` `grow` checks.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        let mut remaining = other;
+        while !remaining.is_empty() {
+            if self.data_blocks.is_empty()
+                || self.data_blocks.last().unwrap().len() == self.data_blocks.last().unwrap().capacity()
+            {
+                self.grow_block();
+            }
+
+            let block = self.data_blocks.last_mut().unwrap();
+            let space = block.capacity() - block.len();
+            let n = space.min(remaining.len());
+            block.extend_from_slice(&remaining[..n]);
+            self.len += n;
+            remaining = &remaining[n..];
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater or equal to the vector's current length, this has no effect. Whole
+    /// data blocks are popped in bulk (reversing the relevant superblock recurrence once per
+    /// block) instead of shrinking one element at a time.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        if len >= self.front_len {
+            self.truncate_back_region(len - self.front_len);
+        } else {
+            self.truncate_back_region(0);
+            self.truncate_front_region(len);
+        }
+    }
+
+    /// Clears the vector, removing all elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the elements specified by the predicate, in place, preserving each region's
+    /// relative order.
+    ///
+    /// `f` is called once per element as [`Self::compact_region_in_place`] sweeps forward through
+    /// each region's blocks in a single pass, swapping survivors down into the earliest free slot
+    /// as it goes; no block is reallocated. The front region is swept in storage order (the order
+    /// elements were pushed via [`Self::push_front`], oldest first), which is the *reverse* of its
+    /// logical order, so for a vector with a non-empty front region `f` is not called in the same
+    /// front-to-back order as [`Self::iter`]; every element is still visited exactly once. Any
+    /// previously reserved blocks are dropped since they may no longer match the recurrence state
+    /// once the block layout changes.
+    ///
+    /// # Panics
+    /// If `f` panics, the vector is left in a valid but unspecified state (some swaps may already
+    /// have been applied), matching the guarantee `Vec::retain` makes for a panicking predicate.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.reserved_blocks.clear();
+        Self::compact_region_in_place(
+            &mut self.front_blocks,
+            &mut self.front_len,
+            &mut self.front_s_odd,
+            &mut self.len_first_super,
+            &mut self.cap_first_super,
+            &mut self.empty_front_block,
+            &mut f,
+        );
+        Self::compact_region_in_place(
+            &mut self.data_blocks,
+            &mut self.len,
+            &mut self.s_odd,
+            &mut self.len_last_super,
+            &mut self.cap_last_super,
+            &mut self.empty_data_block,
+            &mut f,
+        );
+    }
+
+    /// Drops back-region elements beyond the first `keep`, popping whole data blocks in bulk
+    /// (reversing the superblock recurrence block-at-a-time) instead of calling `shrink` once
+    /// per removed element.
+    fn truncate_back_region(&mut self, keep: usize) {
+        if self.len > keep {
+            self.reserved_blocks.clear();
+        }
+        while self.len > keep {
+            let last_block_len = self.data_blocks.last().unwrap().len();
+            if self.len - last_block_len >= keep {
+                let mut block = self.data_blocks.pop().unwrap();
+                block.clear();
+                self.empty_data_block = Some(block);
+                self.len -= last_block_len;
+                self.len_last_super -= 1;
+                if self.len_last_super == 0 {
+                    self.s_odd = !self.s_odd;
+                    if !self.s_odd {
+                        self.cap_last_super /= 2;
+                    }
+                    self.len_last_super = self.cap_last_super;
+                }
+            } else {
+                let new_local_len = last_block_len - (self.len - keep);
+                self.data_blocks.last_mut().unwrap().truncate(new_local_len);
+                self.len = keep;
+            }
+        }
+    }
+
+    /// Drops front-region elements closest to the back/front boundary, down to the first `keep`
+    /// front-most elements. Unlike the back region (where the trailing, boundary-adjacent blocks
+    /// are simply the newest ones and can just be popped), the front region's boundary-adjacent
+    /// elements sit in its *oldest* blocks, at the opposite end from where `grow_front`/
+    /// `shrink_front` operate; keeping only the `keep` newest elements therefore goes through the
+    /// same survivor-repacking path as [`Self::retain`] rather than a plain bulk pop.
+    fn truncate_front_region(&mut self, keep: usize) {
+        let to_drop = self.front_len - keep;
+        let mut seen = 0usize;
+        Self::compact_region_in_place(
+            &mut self.front_blocks,
+            &mut self.front_len,
+            &mut self.front_s_odd,
+            &mut self.len_first_super,
+            &mut self.cap_first_super,
+            &mut self.empty_front_block,
+            &mut |_: &T| {
+                let keep = seen >= to_drop;
+                seen += 1;
+                keep
+            },
+        );
+    }
+
+    /// Shared compaction step behind [`Self::retain`]/[`Self::truncate_front_region`]: sweeps
+    /// `blocks` forward in storage order in a single pass, calling `f` once per element to decide
+    /// whether it survives, and swapping each survivor down into the earliest free slot (tracked
+    /// by a write cursor that never runs ahead of the read position). Every block keeps its
+    /// original allocation and capacity — only element *values* move, via [`std::mem::swap`], so
+    /// nothing is reallocated. Capacities are preserved in block order, so the surviving prefix of
+    /// blocks is automatically a valid canonical capacity sequence for the new, shorter length.
+    ///
+    /// Once the sweep finishes, the write cursor marks exactly how many elements survived: the
+    /// block it landed in is truncated to that point and every block after it — now entirely
+    /// unused — is dropped, before [`Self::block_state_after`] recomputes the superblock
+    /// bookkeeping for the new block count.
+    fn compact_region_in_place<F: FnMut(&T) -> bool>(
+        blocks: &mut Vec<Vec<T>>,
+        len: &mut usize,
+        s_odd: &mut bool,
+        len_last_super: &mut usize,
+        cap_last_super: &mut usize,
+        empty_block: &mut Option<Vec<T>>,
+        f: &mut F,
+    ) {
+        let mut write_block = 0usize;
+        let mut write_pos = 0usize;
+
+        for read_block in 0..blocks.len() {
+            for read_pos in 0..blocks[read_block].len() {
+                if !f(&blocks[read_block][read_pos]) {
+                    continue;
+                }
+                if (write_block, write_pos) != (read_block, read_pos) {
+                    if write_block == read_block {
+                        blocks[read_block].swap(write_pos, read_pos);
+                    } else {
+                        let (left, right) = blocks.split_at_mut(read_block);
+                        std::mem::swap(&mut left[write_block][write_pos], &mut right[0][read_pos]);
+                    }
+                }
+                write_pos += 1;
+                if write_block < blocks.len() && write_pos == blocks[write_block].capacity() {
+                    write_block += 1;
+                    write_pos = 0;
+                }
+            }
+        }
+
+        if write_block < blocks.len() {
+            blocks[write_block].truncate(write_pos);
+            // The first dropped block sits right after the new last block, so it becomes the
+            // spare empty block; a previously held spare belongs to a later position.
+            if write_block + 1 < blocks.len() {
+                let mut next = blocks.swap_remove(write_block + 1);
+                next.clear();
+                *empty_block = Some(next);
+            }
+            blocks.truncate(write_block + 1);
+        }
+        if let Some(last) = blocks.last() {
+            if last.is_empty() {
+                *empty_block = blocks.pop();
+            }
+        }
+
+        *len = blocks.iter().map(|b| b.len()).sum();
+        let (new_s_odd, new_len_last_super, new_cap_last_super) = Self::block_state_after(blocks.len());
+        *s_odd = new_s_odd;
+        *len_last_super = new_len_last_super;
+        *cap_last_super = new_cap_last_super;
+
+        if blocks.is_empty() && empty_block.is_none() {
+            *empty_block = Some(Vec::with_capacity(1));
+        }
+    }
+
+    /// Replays [`Self::next_block_state`] from the bootstrap state `n_blocks` times, returning the
+    /// `(s_odd, len_last_super, cap_last_super)` a region would have if it had been built from
+    /// empty via ordinary pushes up to exactly `n_blocks` data blocks. Used by
+    /// [`Self::compact_region_in_place`] to restore bookkeeping after compacting a region's block
+    /// layout.
+    fn block_state_after(n_blocks: usize) -> (bool, usize, usize) {
+        if n_blocks == 0 {
+            return (true, 0, 1);
+        }
+        let mut s_odd = true;
+        let mut len_last_super = 1;
+        let mut cap_last_super = 1;
+        let mut last_cap = 1;
+        for _ in 1..n_blocks {
+            let (next_s_odd, next_len_last_super, next_cap_last_super, cap) =
+                Self::next_block_state(s_odd, len_last_super, cap_last_super, last_cap);
+            s_odd = next_s_odd;
+            len_last_super = next_len_last_super;
+            cap_last_super = next_cap_last_super;
+            last_cap = cap;
+        }
+        (s_odd, len_last_super, cap_last_super)
+    }
+
     pub fn simple_sanity_check(&self) {
         if self.is_empty() {
             return;
         }
         // We count the number of elements in the vectors and we check that every vector except the last one(s) are full
-        let length = self.data_blocks.iter().map(|vec| vec.len()).sum();
-        let result = self.len() == length;
-        assert!(result);
+        let back_length: usize = self.data_blocks.iter().map(|vec| vec.len()).sum();
+        assert_eq!(self.len, back_length);
 
-        for idx in 0..self.data_blocks.len() - 1 {
-            let vec = &self.data_blocks[idx];
-            assert_eq!(vec.capacity(), vec.len());
+        if !self.data_blocks.is_empty() {
+            for idx in 0..self.data_blocks.len() - 1 {
+                let vec = &self.data_blocks[idx];
+                assert_eq!(vec.capacity(), vec.len());
+            }
         }
 
+        // The front region mirrors the same invariant: every front data block except the last is full.
+        let front_length: usize = self.front_blocks.iter().map(|vec| vec.len()).sum();
+        assert_eq!(self.front_len, front_length);
+
+        if !self.front_blocks.is_empty() {
+            for idx in 0..self.front_blocks.len() - 1 {
+                let vec = &self.front_blocks[idx];
+                assert_eq!(vec.capacity(), vec.len());
+            }
+        }
     }
 }
 
+/// A draining iterator for [`SuccinctVec<T>`], created by [`SuccinctVec::drain`].
+pub struct Drain<'a, T> {
+    iter: std::vec::IntoIter<T>,
+    _marker: std::marker::PhantomData<&'a mut SuccinctVec<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> std::iter::FusedIterator for Drain<'a, T> {}
+
 type VecIter<T> = std::vec::IntoIter<T>;
-pub type SuccinctIter<T> = std::iter::FlatMap<VecIter<Vec<T>>, VecIter<T>, fn(Vec<T>) -> VecIter<T>>;
+type RevVecIter<T> = std::iter::Rev<std::vec::IntoIter<T>>;
+type FrontIter<T> = std::iter::FlatMap<std::iter::Rev<VecIter<Vec<T>>>, RevVecIter<T>, fn(Vec<T>) -> RevVecIter<T>>;
+type BackIter<T> = std::iter::FlatMap<VecIter<Vec<T>>, VecIter<T>, fn(Vec<T>) -> VecIter<T>>;
+pub type SuccinctIter<T> = std::iter::Chain<FrontIter<T>, BackIter<T>>;
 
 impl<T> IntoIterator for SuccinctVec<T> {
     type Item = T;
     type IntoIter = SuccinctIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data_blocks.into_iter().flat_map(IntoIterator::into_iter)
+        let rev_block: fn(Vec<T>) -> RevVecIter<T> = |block| block.into_iter().rev();
+        let front = self.front_blocks.into_iter().rev().flat_map(rev_block);
+        let back = self.data_blocks.into_iter().flat_map(IntoIterator::into_iter as fn(Vec<T>) -> VecIter<T>);
+        front.chain(back)
+    }
+}
+
+impl<T> Extend<T> for SuccinctVec<T> {
+    /// Reserves space for the iterator's `size_hint` lower bound up front (if any), then pushes
+    /// each element, so the pushes reuse pre-allocated blocks instead of allocating as they go.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        if lower > 0 {
+            self.reserve(lower);
+        }
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for SuccinctVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for SuccinctVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::default();
+        vec.extend(iter);
+        vec
     }
 }
 
@@ -263,15 +981,25 @@ impl<T> Index<usize> for SuccinctVec<T> {
     type Output = T;
 
     fn index(&self, i: usize) -> &T {
-        let (a, b) = Self::locate(i);
-        &self.data_blocks[a][b]
+        if i < self.front_len {
+            let (a, b) = Self::locate(self.front_len - 1 - i);
+            &self.front_blocks[a][b]
+        } else {
+            let (a, b) = Self::locate(i - self.front_len);
+            &self.data_blocks[a][b]
+        }
     }
 }
 
 impl<T> IndexMut<usize> for SuccinctVec<T> {
     fn index_mut(&mut self, i: usize) -> &mut T {
-        let (a, b) = Self::locate(i);
-        &mut self.data_blocks[a][b]
+        if i < self.front_len {
+            let (a, b) = Self::locate(self.front_len - 1 - i);
+            &mut self.front_blocks[a][b]
+        } else {
+            let (a, b) = Self::locate(i - self.front_len);
+            &mut self.data_blocks[a][b]
+        }
     }
 }
 
@@ -284,6 +1012,224 @@ impl<T> Default for SuccinctVec<T> {
             len_last_super: 0,
             cap_last_super: 1,
             empty_data_block: Some(Vec::with_capacity(1)),
+            reserved_blocks: std::collections::VecDeque::new(),
+            front_blocks: Vec::new(),
+            front_len: 0,
+            front_s_odd: true,
+            len_first_super: 0,
+            cap_first_super: 1,
+            empty_front_block: Some(Vec::with_capacity(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for a `reserve_simulation_state`/`grow_block` ordering bug: right after a
+    /// `pop` empties the last data block, `data_blocks.last()` and `empty_data_block` are both
+    /// `Some` at once, and `reserve` used to size every subsequently reserved block one position
+    /// too early in the canonical recurrence, desyncing `locate` once a superblock boundary was
+    /// crossed.
+    #[test]
+    fn reserve_after_pop_at_block_boundary_matches_vec() {
+        for k in 1..=64usize {
+            let mut v: SuccinctVec<i32> = SuccinctVec::default();
+            for i in 0..k as i32 {
+                v.push(i);
+            }
+            v.pop();
+            v.reserve(400);
+            for i in 0..400i32 {
+                v.push(i + 1000);
+            }
+
+            let mut reference: Vec<i32> = (0..k as i32 - 1).collect();
+            reference.extend((0..400i32).map(|i| i + 1000));
+
+            assert_eq!(v.len(), reference.len(), "length mismatch at k={k}");
+            for (idx, expected) in reference.iter().enumerate() {
+                assert_eq!(v[idx], *expected, "mismatch at k={k}, idx={idx}");
+            }
+        }
+    }
+
+    /// `Extend::extend` calls `self.reserve(lower)`, so it inherits the reserve-after-pop bug
+    /// above; this is the scenario the maintainer reported as panicking.
+    #[test]
+    fn extend_after_pop_at_block_boundary_matches_vec() {
+        for k in [2usize, 4, 6, 8, 12, 16, 20, 24] {
+            let mut v: SuccinctVec<i32> = SuccinctVec::default();
+            for i in 0..k as i32 {
+                v.push(i);
+            }
+            v.pop();
+            v.extend(0..400i32);
+
+            let mut reference: Vec<i32> = (0..k as i32 - 1).collect();
+            reference.extend(0..400i32);
+
+            assert_eq!(v.len(), reference.len(), "length mismatch at k={k}");
+            for (idx, expected) in reference.iter().enumerate() {
+                assert_eq!(v[idx], *expected, "mismatch at k={k}, idx={idx}");
+            }
+        }
+    }
+
+    /// `chunks`/`chunks_mut` only ever covered the back region, silently dropping front-region
+    /// elements after `push_front`; they now debug-assert instead of returning an incomplete view.
+    #[test]
+    fn chunks_covers_back_region() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        for i in 0..20 {
+            v.push(i);
+        }
+        let collected: Vec<i32> = v.chunks().flat_map(|s| s.iter().copied()).collect();
+        assert_eq!(collected, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunks() only covers the back region")]
+    fn chunks_panics_when_front_region_nonempty() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        v.push_front(1);
+        v.push(2);
+        let _ = v.chunks().count();
+    }
+
+    /// Regression test for a second `reserve`/`grow_block` desync: `reserve` doesn't know a
+    /// `pop` will later rewind the recurrence, so a block it queued up in `reserved_blocks` can
+    /// be the wrong size for the slot `grow_block` actually needs it for once that pop happens.
+    #[test]
+    fn reserve_then_pop_then_push_matches_vec() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        for i in 0..20i32 {
+            v.push(i);
+        }
+        v.reserve(200);
+        for _ in 0..10 {
+            v.pop();
+        }
+        v.push(200);
+
+        let mut reference: Vec<i32> = (0..10i32).collect();
+        reference.push(200);
+
+        assert_eq!(v.len(), reference.len());
+        for (idx, expected) in reference.iter().enumerate() {
+            assert_eq!(v[idx], *expected, "mismatch at idx={idx}");
+        }
+    }
+
+    #[test]
+    fn retain_back_region_only_matches_vec() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        for i in 0..37i32 {
+            v.push(i);
+        }
+        v.retain(|&x| x % 3 != 0);
+
+        let mut reference: Vec<i32> = (0..37i32).collect();
+        reference.retain(|&x| x % 3 != 0);
+
+        assert_eq!(v.len(), reference.len());
+        for (idx, expected) in reference.iter().enumerate() {
+            assert_eq!(v[idx], *expected, "mismatch at idx={idx}");
+        }
+    }
+
+    #[test]
+    fn retain_front_and_back_region_matches_deque() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        let mut reference: std::collections::VecDeque<i32> = std::collections::VecDeque::new();
+        for i in 0..15i32 {
+            v.push(i);
+            reference.push_back(i);
+        }
+        for i in 1..13i32 {
+            v.push_front(-i);
+            reference.push_front(-i);
+        }
+
+        v.retain(|&x| x % 2 == 0);
+        reference.retain(|&x| x % 2 == 0);
+
+        let expected: Vec<i32> = reference.into_iter().collect();
+        assert_eq!(v.len(), expected.len());
+        for (idx, value) in v.iter().enumerate() {
+            assert_eq!(*value, expected[idx], "mismatch at idx={idx}");
+        }
+    }
+
+    #[test]
+    fn retain_then_push_stays_consistent_with_vec() {
+        let mut v: SuccinctVec<i32> = SuccinctVec::default();
+        for i in 0..50i32 {
+            v.push(i);
+        }
+        v.retain(|&x| x % 5 != 0);
+        for i in 50..70i32 {
+            v.push(i);
+        }
+
+        let mut reference: Vec<i32> = (0..50i32).collect();
+        reference.retain(|&x| x % 5 != 0);
+        reference.extend(50..70i32);
+
+        assert_eq!(v.len(), reference.len());
+        for (idx, expected) in reference.iter().enumerate() {
+            assert_eq!(v[idx], *expected, "mismatch at idx={idx}");
+        }
+    }
+
+    #[test]
+    fn retain_fuzz_matches_vecdeque() {
+        fn lcg(state: &mut u64) -> u64 {
+            *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            *state
+        }
+
+        let mut state = 0xC0FFEEu64;
+        for trial in 0..40 {
+            let mut v: SuccinctVec<i32> = SuccinctVec::default();
+            let mut reference: std::collections::VecDeque<i32> = std::collections::VecDeque::new();
+            let mut next_val = 0i32;
+
+            for _ in 0..200 {
+                match lcg(&mut state) % 4 {
+                    0 => {
+                        v.push(next_val);
+                        reference.push_back(next_val);
+                        next_val += 1;
+                    }
+                    1 => {
+                        v.push_front(next_val);
+                        reference.push_front(next_val);
+                        next_val += 1;
+                    }
+                    2 => {
+                        let modulus = 1 + (lcg(&mut state) % 5) as i32;
+                        v.retain(|&x| x % modulus != 0);
+                        reference.retain(|&x| x % modulus != 0);
+                    }
+                    _ => {
+                        if !reference.is_empty() && lcg(&mut state) % 2 == 0 {
+                            v.pop();
+                            reference.pop_back();
+                        } else if !reference.is_empty() {
+                            v.pop_front();
+                            reference.pop_front();
+                        }
+                    }
+                }
+            }
+
+            let expected: Vec<i32> = reference.into_iter().collect();
+            assert_eq!(v.len(), expected.len(), "len mismatch on trial {trial}");
+            for (idx, value) in v.iter().enumerate() {
+                assert_eq!(*value, expected[idx], "mismatch at idx={idx} on trial {trial}");
+            }
         }
     }
 }